@@ -0,0 +1,90 @@
+use clap::{Parser, Subcommand};
+use clap_complete::Shell;
+
+#[derive(Parser)]
+#[command(
+    name = "jbundle",
+    about = "Bundle a JVM application into a single self-contained executable",
+    version
+)]
+pub struct Cli {
+    /// Output language (e.g. en, pt-BR); defaults to LC_ALL/LANG, then English
+    #[arg(long, global = true)]
+    pub lang: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Build a self-contained executable from a project or JAR
+    Build {
+        /// Project directory or pre-built JAR
+        #[arg(default_value = ".")]
+        input: String,
+
+        /// Output binary path
+        #[arg(short, long, default_value = "app")]
+        output: String,
+
+        /// Java feature version (e.g. 17, 21)
+        #[arg(long)]
+        java_version: Option<u32>,
+
+        /// Target platform (linux-x64, linux-aarch64, macos-x64, macos-aarch64,
+        /// windows-x64, windows-aarch64)
+        #[arg(
+            long,
+            value_parser = [
+                "linux-x64", "linux-aarch64", "macos-x64", "macos-aarch64",
+                "windows-x64", "windows-aarch64"
+            ]
+        )]
+        target: Option<String>,
+
+        /// Extra JVM arguments baked into the launcher
+        #[arg(long = "jvm-arg", value_name = "ARG")]
+        jvm_args: Vec<String>,
+
+        /// Shrink the uberjar before bundling
+        #[arg(long)]
+        shrink: bool,
+
+        /// JVM profile (server, desktop); validated, but the launcher adds no
+        /// profile-specific flags
+        #[arg(long, value_parser = ["server", "desktop"])]
+        profile: Option<String>,
+
+        /// Disable AppCDS class-data sharing; the packed launcher does not use
+        /// AppCDS either way
+        #[arg(long)]
+        no_appcds: bool,
+
+        /// Create a CRaC checkpoint and bundle it; the launcher does not
+        /// restore from it
+        #[arg(long)]
+        crac: bool,
+
+        /// Increase diagnostic verbosity (-v info, -vv debug, -vvv trace)
+        #[arg(short, long, action = clap::ArgAction::Count)]
+        verbose: u8,
+
+        /// Use the compact startup banner; the packed launcher always prints
+        /// the full banner
+        #[arg(long)]
+        compact_banner: bool,
+    },
+    /// Remove all cached JDKs and runtimes
+    Clean,
+    /// Show cache location and size
+    Info,
+    /// Probe the build environment and report what this host can produce
+    Doctor,
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
@@ -0,0 +1,120 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+
+/// A platform jbundle can produce a runnable artifact for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    LinuxX64,
+    LinuxAarch64,
+    MacosX64,
+    MacosAarch64,
+    WindowsX64,
+    WindowsAarch64,
+}
+
+impl Target {
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "linux-x64" => Ok(Target::LinuxX64),
+            "linux-aarch64" => Ok(Target::LinuxAarch64),
+            "macos-x64" => Ok(Target::MacosX64),
+            "macos-aarch64" => Ok(Target::MacosAarch64),
+            "windows-x64" => Ok(Target::WindowsX64),
+            "windows-aarch64" => Ok(Target::WindowsAarch64),
+            other => Err(anyhow!("unknown target: {other}")),
+        }
+    }
+
+    /// The canonical `os-arch` slug for this target.
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Target::LinuxX64 => "linux-x64",
+            Target::LinuxAarch64 => "linux-aarch64",
+            Target::MacosX64 => "macos-x64",
+            Target::MacosAarch64 => "macos-aarch64",
+            Target::WindowsX64 => "windows-x64",
+            Target::WindowsAarch64 => "windows-aarch64",
+        }
+    }
+
+    /// Whether this target runs on Windows and needs the `.cmd` launcher
+    /// rather than the POSIX shell stub.
+    pub fn is_windows(&self) -> bool {
+        matches!(self, Target::WindowsX64 | Target::WindowsAarch64)
+    }
+
+    /// The target matching the host this process is running on.
+    pub fn current() -> Self {
+        match (std::env::consts::OS, std::env::consts::ARCH) {
+            ("macos", "aarch64") => Target::MacosAarch64,
+            ("macos", _) => Target::MacosX64,
+            ("windows", "aarch64") => Target::WindowsAarch64,
+            ("windows", _) => Target::WindowsX64,
+            (_, "aarch64") => Target::LinuxAarch64,
+            _ => Target::LinuxX64,
+        }
+    }
+
+    /// All targets known to jbundle.
+    pub fn all() -> &'static [Target] {
+        &[
+            Target::LinuxX64,
+            Target::LinuxAarch64,
+            Target::MacosX64,
+            Target::MacosAarch64,
+            Target::WindowsX64,
+            Target::WindowsAarch64,
+        ]
+    }
+
+    /// Whether this target can be produced on the current host. jlink bundles
+    /// the native launcher and libraries of the JDK it runs on, so only the
+    /// host's own os/arch can be produced from this machine.
+    pub fn buildable_on_host(&self) -> bool {
+        *self == Target::current()
+    }
+}
+
+/// JVM tuning profile applied to the generated launcher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JvmProfile {
+    Server,
+    Desktop,
+}
+
+impl JvmProfile {
+    pub fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "server" => Ok(JvmProfile::Server),
+            "desktop" => Ok(JvmProfile::Desktop),
+            other => Err(anyhow!("unknown profile: {other}")),
+        }
+    }
+}
+
+/// Fully resolved configuration for a single `build` invocation.
+pub struct BuildConfig {
+    pub input: PathBuf,
+    pub output: PathBuf,
+    pub java_version: u32,
+    pub java_version_explicit: bool,
+    pub target: Target,
+    pub jvm_args: Vec<String>,
+    pub shrink: bool,
+    pub profile: JvmProfile,
+    pub appcds: bool,
+    pub crac: bool,
+    pub compact_banner: bool,
+    pub verbosity: u8,
+}
+
+impl BuildConfig {
+    /// The directory where downloaded JDKs and built runtimes are cached.
+    pub fn cache_dir() -> Result<PathBuf> {
+        let home = std::env::var_os("HOME")
+            .map(PathBuf::from)
+            .ok_or_else(|| anyhow!("HOME is not set"))?;
+        Ok(home.join(".jbundle").join("cache"))
+    }
+}
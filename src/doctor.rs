@@ -0,0 +1,185 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Result;
+use console::style;
+
+use crate::config::{BuildConfig, Target};
+
+/// Outcome of a single environment probe.
+enum Check {
+    /// The requirement is satisfied.
+    Ok(String),
+    /// Non-fatal: a build can still run, but something is worth knowing.
+    Warn(String),
+    /// A hard prerequisite is missing; `doctor` will exit non-zero.
+    Fail(String),
+}
+
+impl Check {
+    fn print(&self, label: &str) {
+        match self {
+            Check::Ok(detail) => {
+                eprintln!("  {} {label}: {detail}", style("✓").green());
+            }
+            Check::Warn(detail) => {
+                eprintln!("  {} {label}: {detail}", style("⚠").yellow());
+            }
+            Check::Fail(detail) => {
+                eprintln!("  {} {label}: {detail}", style("✗").red());
+            }
+        }
+    }
+
+    fn is_fail(&self) -> bool {
+        matches!(self, Check::Fail(_))
+    }
+}
+
+/// Probe the host and print a structured report of what jbundle can do here.
+/// Returns `Err` if any hard prerequisite for a build is missing so the
+/// process exits non-zero.
+pub fn run() -> Result<()> {
+    eprintln!("{}", style("jbundle doctor").bold());
+
+    let mut failed = false;
+
+    eprintln!("\n{}", style("Java toolchains").bold());
+    let java = tool_version("java", "-version");
+    match &java {
+        Some(v) => Check::Ok(v.clone()),
+        None => Check::Warn("not found — jbundle will download one on first build".into()),
+    }
+    .print("java on PATH");
+
+    let jlink = tool_version("jlink", "--version");
+    match &jlink {
+        Some(v) => Check::Ok(v.clone()),
+        None => Check::Warn("not on PATH — jbundle uses the jlink from its downloaded JDK".into()),
+    }
+    .print("jlink");
+
+    let cached = cached_jdks();
+    if cached.is_empty() {
+        Check::Warn("no cached JDKs yet".into()).print("cached toolchain");
+    } else {
+        for name in &cached {
+            Check::Ok(name.clone()).print("cached toolchain");
+        }
+    }
+
+    // Hard prerequisite: producing a runtime needs a JDK toolchain. A JDK on
+    // PATH or any cached JDK counts as present. `jbundle build` itself uses
+    // only the cached JDK for the requested version and target and downloads
+    // it when missing, so a pass here does not mean the build runs offline.
+    if java.is_some() || jlink.is_some() || !cached.is_empty() {
+        Check::Ok("a JDK toolchain is available locally".into()).print("build toolchain");
+    } else {
+        Check::Fail(
+            "no JDK on PATH and none cached — run `jbundle build` once with network access".into(),
+        )
+        .print("build toolchain");
+        failed = true;
+    }
+
+    eprintln!("\n{}", style("Build tools").bold());
+    for (tool, arg) in [
+        ("mvn", "--version"),
+        ("gradle", "--version"),
+        ("clojure", "--version"),
+        ("lein", "--version"),
+    ] {
+        probe_tool(tool, arg).print(tool);
+    }
+
+    eprintln!("\n{}", style("Targets").bold());
+    for target in Target::all() {
+        let check = if target.buildable_on_host() {
+            Check::Ok("buildable on this host".into())
+        } else {
+            Check::Warn("not the host's os/arch, not buildable here".into())
+        };
+        check.print(target.slug());
+    }
+
+    eprintln!("\n{}", style("Features").bold());
+    probe_crac().print("CRaC");
+
+    eprintln!("\n{}", style("Project").bold());
+    let project = probe_project_config();
+    failed |= project.is_fail();
+    project.print("jbundle.toml");
+
+    if failed {
+        eprintln!(
+            "\n{} some prerequisites are missing; `jbundle build` may fail here",
+            style("✗").red()
+        );
+        anyhow::bail!("environment check failed");
+    }
+
+    eprintln!("\n{} environment looks good", style("✓").green());
+    Ok(())
+}
+
+/// Names of cached JDKs that contain a usable `java` launcher. Empty when the
+/// cache is missing or holds no complete toolchain.
+fn cached_jdks() -> Vec<String> {
+    let cache_dir = match BuildConfig::cache_dir() {
+        Ok(dir) if dir.exists() => dir,
+        _ => return Vec::new(),
+    };
+
+    let mut found = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(&cache_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            // JDKs land at <root>/bin/java on linux and Contents/Home/bin/java
+            // inside a macOS bundle.
+            let has_java = path.join("bin").join("java").exists()
+                || path.join("Contents").join("Home").join("bin").join("java").exists();
+            if has_java {
+                found.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+    }
+    found
+}
+
+fn probe_tool(tool: &str, arg: &str) -> Check {
+    match tool_version(tool, arg) {
+        Some(v) => Check::Ok(v),
+        None => Check::Warn("not installed".into()),
+    }
+}
+
+fn probe_crac() -> Check {
+    if std::env::consts::OS != "linux" {
+        return Check::Warn("unavailable (requires Linux)".into());
+    }
+    Check::Warn("available on Linux, pending a CRaC-enabled JVM".into())
+}
+
+fn probe_project_config() -> Check {
+    let cwd = std::env::current_dir().unwrap_or_else(|_| Path::new(".").to_path_buf());
+    match crate::project_config::load_project_config(&cwd) {
+        Ok(Some(_)) => Check::Ok("parsed and resolved".into()),
+        Ok(None) => Check::Warn("no jbundle.toml (using CLI defaults)".into()),
+        Err(e) => Check::Fail(format!("failed to parse: {e}")),
+    }
+}
+
+/// Run `<tool> <arg>` and return the first non-empty line of its output, or
+/// `None` if the tool is not installed.
+fn tool_version(tool: &str, arg: &str) -> Option<String> {
+    let output = Command::new(tool).arg(arg).output().ok()?;
+    let text = if output.stdout.is_empty() {
+        String::from_utf8_lossy(&output.stderr)
+    } else {
+        String::from_utf8_lossy(&output.stdout)
+    };
+    text.lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .map(str::to_string)
+}
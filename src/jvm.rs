@@ -0,0 +1,156 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+
+use crate::config::{BuildConfig, Target};
+
+/// How the Adoptium API names a target's operating system, architecture, and
+/// the archive format it ships the JDK in (`.zip` on Windows, `.tar.gz`
+/// elsewhere).
+struct JdkCoords {
+    os: &'static str,
+    arch: &'static str,
+    archive_ext: &'static str,
+}
+
+fn coords_for(target: &Target) -> JdkCoords {
+    match target {
+        Target::LinuxX64 => JdkCoords {
+            os: "linux",
+            arch: "x64",
+            archive_ext: "tar.gz",
+        },
+        Target::LinuxAarch64 => JdkCoords {
+            os: "linux",
+            arch: "aarch64",
+            archive_ext: "tar.gz",
+        },
+        Target::MacosX64 => JdkCoords {
+            os: "mac",
+            arch: "x64",
+            archive_ext: "tar.gz",
+        },
+        Target::MacosAarch64 => JdkCoords {
+            os: "mac",
+            arch: "aarch64",
+            archive_ext: "tar.gz",
+        },
+        Target::WindowsX64 => JdkCoords {
+            os: "windows",
+            arch: "x64",
+            archive_ext: "zip",
+        },
+        Target::WindowsAarch64 => JdkCoords {
+            os: "windows",
+            arch: "aarch64",
+            archive_ext: "zip",
+        },
+    }
+}
+
+/// The Adoptium Temurin download URL for a JDK feature version and target.
+fn download_url(version: u32, target: &Target) -> String {
+    let c = coords_for(target);
+    format!(
+        "https://api.adoptium.net/v3/binary/latest/{version}/ga/{}/{}/jdk/hotspot/normal/eclipse",
+        c.os, c.arch
+    )
+}
+
+/// Ensure a JDK for `version` and `target` is present in the cache, downloading
+/// and extracting it if necessary, and return the path to its home directory.
+pub async fn ensure_jdk(version: u32, target: &Target, mp: &MultiProgress) -> Result<PathBuf> {
+    let cache_dir = BuildConfig::cache_dir()?;
+    let jdk_dir = cache_dir.join(format!("jdk-{version}-{}", target.slug()));
+    // macOS archives keep the JDK home under Contents/Home inside the bundle.
+    let home_of = |dir: &std::path::Path| {
+        let mac_home = dir.join("Contents").join("Home");
+        if mac_home.is_dir() { mac_home } else { dir.to_path_buf() }
+    };
+    let home = home_of(&jdk_dir);
+    if home.join("bin").exists() {
+        return Ok(home);
+    }
+
+    let coords = coords_for(target);
+    let url = download_url(version, target);
+
+    let pb = mp.add(ProgressBar::new_spinner());
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .template("      {spinner:.cyan} {msg}")
+            .expect("invalid spinner template"),
+    );
+    pb.set_message(format!("fetching {url}"));
+    pb.enable_steady_tick(std::time::Duration::from_millis(80));
+
+    let bytes = reqwest::get(&url)
+        .await
+        .with_context(|| format!("downloading JDK from {url}"))?
+        .error_for_status()
+        .with_context(|| format!("no JDK {version} build for {}", target.slug()))?
+        .bytes()
+        .await
+        .context("reading JDK archive")?;
+
+    std::fs::create_dir_all(&cache_dir)?;
+    let tmp = cache_dir.join(format!(".jdk-{version}-{}.tmp", target.slug()));
+    extract_archive(&bytes, coords.archive_ext, &tmp)
+        .with_context(|| format!("extracting JDK archive ({})", coords.archive_ext))?;
+
+    // Adoptium archives contain a single top-level `jdk-<ver>` directory.
+    let root = single_child_dir(&tmp)?;
+    if jdk_dir.exists() {
+        std::fs::remove_dir_all(&jdk_dir)?;
+    }
+    std::fs::rename(&root, &jdk_dir)?;
+    let _ = std::fs::remove_dir_all(&tmp);
+
+    pb.finish_and_clear();
+    Ok(home_of(&jdk_dir))
+}
+
+fn extract_archive(bytes: &[u8], archive_ext: &str, dest: &std::path::Path) -> Result<()> {
+    std::fs::create_dir_all(dest)?;
+    if archive_ext == "zip" {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes))?;
+        archive.extract(dest)?;
+    } else {
+        let decoder = flate2::read::GzDecoder::new(bytes);
+        let mut archive = tar::Archive::new(decoder);
+        archive.unpack(dest)?;
+    }
+    Ok(())
+}
+
+fn single_child_dir(dir: &std::path::Path) -> Result<PathBuf> {
+    let mut children = std::fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_dir());
+    children
+        .next()
+        .context("JDK archive had no top-level directory")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_target_resolves_windows_zip_archive() {
+        let coords = coords_for(&Target::WindowsX64);
+        assert_eq!(coords.os, "windows");
+        assert_eq!(coords.archive_ext, "zip");
+
+        let url = download_url(21, &Target::WindowsX64);
+        assert!(url.contains("/windows/x64/"));
+    }
+
+    #[test]
+    fn posix_targets_resolve_tarball_archives() {
+        assert_eq!(coords_for(&Target::LinuxAarch64).archive_ext, "tar.gz");
+        assert_eq!(coords_for(&Target::MacosX64).os, "mac");
+    }
+}
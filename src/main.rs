@@ -4,9 +4,11 @@ mod config;
 mod crac;
 mod detect;
 mod diagnostic;
+mod doctor;
 mod error;
 mod jlink;
 mod jvm;
+mod messages;
 mod pack;
 mod progress;
 mod project_config;
@@ -16,24 +18,30 @@ mod validate;
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use clap_complete::Shell;
 use indicatif::HumanBytes;
 
 use cli::{Cli, Command};
 use config::{BuildConfig, JvmProfile, Target};
+use messages::{Catalog, Locale};
 use progress::Pipeline;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    // Extract verbose flag before initializing tracing
-    let verbose = matches!(&cli.command, Command::Build { verbose: true, .. });
+    // Extract verbosity before initializing tracing
+    let verbosity = match &cli.command {
+        Command::Build { verbose, .. } => *verbose,
+        _ => 0,
+    };
 
-    let default_level = if verbose {
-        "jbundle=info"
-    } else {
-        "jbundle=warn"
+    let default_level = match verbosity {
+        0 => "jbundle=warn",
+        1 => "jbundle=info",
+        2 => "jbundle=debug",
+        _ => "jbundle=trace",
     };
     tracing_subscriber::fmt()
         .with_env_filter(
@@ -44,6 +52,8 @@ async fn main() -> Result<()> {
         .without_time()
         .init();
 
+    let catalog = Catalog::new(Locale::resolve(cli.lang.as_deref()));
+
     match cli.command {
         Command::Build {
             input,
@@ -71,11 +81,11 @@ async fn main() -> Result<()> {
 
             let target = match target {
                 Some(t) => Target::from_str(&t).context(format!(
-                    "invalid target: {t}. Use: linux-x64, linux-aarch64, macos-x64, macos-aarch64"
+                    "invalid target: {t}. Use: linux-x64, linux-aarch64, macos-x64, macos-aarch64, windows-x64, windows-aarch64"
                 ))?,
                 None => match project_config.as_ref().and_then(|c| c.target.as_deref()) {
                     Some(t) => Target::from_str(t).context(format!(
-                        "invalid target in jbundle.toml: {t}. Use: linux-x64, linux-aarch64, macos-x64, macos-aarch64"
+                        "invalid target in jbundle.toml: {t}. Use: linux-x64, linux-aarch64, macos-x64, macos-aarch64, windows-x64, windows-aarch64"
                     ))?,
                     None => Target::current(),
                 },
@@ -144,15 +154,22 @@ async fn main() -> Result<()> {
                 appcds,
                 crac,
                 compact_banner,
+                verbosity,
             };
 
-            run_build(config).await?;
+            run_build(config, &catalog).await?;
         }
         Command::Clean => {
-            run_clean()?;
+            run_clean(&catalog)?;
         }
         Command::Info => {
-            run_info()?;
+            run_info(&catalog)?;
+        }
+        Command::Doctor => {
+            doctor::run()?;
+        }
+        Command::Completions { shell } => {
+            run_completions(shell);
         }
     }
 
@@ -166,26 +183,26 @@ fn calculate_steps(is_jar_input: bool, shrink: bool, crac: bool) -> usize {
     base + shrink_step + 4 + crac_step // +4 = JDK, jdeps, jlink, pack
 }
 
-async fn run_build(config: BuildConfig) -> Result<()> {
+async fn run_build(config: BuildConfig, catalog: &Catalog) -> Result<()> {
     let is_jar_input = config.input.extension().is_some_and(|e| e == "jar");
     let total_steps = calculate_steps(is_jar_input, config.shrink, config.crac);
-    let mut pipeline = Pipeline::new(total_steps);
+    let mut pipeline = Pipeline::new(total_steps, config.verbosity);
 
     eprintln!();
 
     // Step: Detect build system (only for project directories)
     let jar_path = if is_jar_input {
-        let step = pipeline.start_step("Using pre-built JAR");
+        let step = pipeline.start_step(catalog.using_prebuilt_jar());
         let jar = config.input.clone();
         Pipeline::finish_step(&step, &format!("JAR: {}", jar.display()));
         jar
     } else {
-        let step = pipeline.start_step("Detecting build system");
+        let step = pipeline.start_step(catalog.detecting_build_system());
         let system = detect::detect_build_system(&config.input)?;
         Pipeline::finish_step(&step, &format!("{:?}", system));
 
         let build_desc = build::build_command_description(system);
-        let step = pipeline.start_step(&format!("Building uberjar ({})", build_desc));
+        let step = pipeline.start_step(&catalog.building_uberjar(build_desc));
         let jar = build::build_uberjar(&config.input, system)?;
         Pipeline::finish_step(
             &step,
@@ -196,7 +213,7 @@ async fn run_build(config: BuildConfig) -> Result<()> {
 
     // Step: Shrink JAR (optional)
     let jar_path = if config.shrink {
-        let step = pipeline.start_step("Shrinking JAR");
+        let step = pipeline.start_step(catalog.shrinking_jar());
         let result = shrink::shrink_jar(&jar_path)?;
         if result.shrunk_size < result.original_size {
             let reduction = result.original_size - result.shrunk_size;
@@ -227,25 +244,28 @@ async fn run_build(config: BuildConfig) -> Result<()> {
     )?;
 
     // Step: Download/ensure JDK
-    let step = pipeline.start_step(&format!("Downloading JDK {}", java_version));
+    let step = pipeline.start_step(&catalog.downloading_jdk(java_version));
     let jdk_path = jvm::ensure_jdk(java_version, &config.target, pipeline.mp()).await?;
     Pipeline::finish_step(&step, "ready");
+    pipeline.detail(2, || format!("JDK home: {}", jdk_path.display()));
 
     // Step: Detect modules (jdeps)
-    let step = pipeline.start_step("Analyzing module dependencies");
+    let step = pipeline.start_step(catalog.analyzing_modules());
     let temp_dir = tempfile::tempdir()?;
     let modules = jlink::detect_modules(&jdk_path, &jar_path)?;
     let module_count = modules.split(',').count();
     Pipeline::finish_step(&step, &format!("{} modules", module_count));
+    pipeline.detail(2, || format!("jdeps modules: {modules}"));
 
     // Step: Create minimal runtime (jlink)
-    let step = pipeline.start_step("Creating minimal runtime (jlink)");
+    let step = pipeline.start_step(catalog.creating_runtime());
     let runtime_path = jlink::create_runtime(&jdk_path, &modules, temp_dir.path())?;
     Pipeline::finish_step(&step, "done");
+    pipeline.detail(2, || format!("runtime image: {}", runtime_path.display()));
 
     // Step: CRaC checkpoint (optional)
     let crac_path = if config.crac {
-        let step = pipeline.start_step("Creating CRaC checkpoint");
+        let step = pipeline.start_step(catalog.creating_checkpoint());
         match crac::create_checkpoint(&runtime_path, &jdk_path, &jar_path, temp_dir.path()) {
             Ok(cp) => {
                 let cp_size = std::fs::metadata(&cp)?.len();
@@ -264,8 +284,8 @@ async fn run_build(config: BuildConfig) -> Result<()> {
     let compact_banner = config.compact_banner;
 
     // Step: Pack binary
-    let step = pipeline.start_step("Packing binary");
-    pack::create_binary(&pack::PackOptions {
+    let step = pipeline.start_step(catalog.packing_binary());
+    let output_path = pack::create_binary(&pack::PackOptions {
         runtime_dir: &runtime_path,
         jar_path: &jar_path,
         crac_path: crac_path.as_deref(),
@@ -275,42 +295,50 @@ async fn run_build(config: BuildConfig) -> Result<()> {
         appcds: config.appcds,
         java_version,
         compact_banner,
+        target: &config.target,
+        extracting_msg: catalog.extracting_runtime(),
     })?;
-    let size = std::fs::metadata(&config.output)?.len();
+    let size = std::fs::metadata(&output_path)?.len();
     Pipeline::finish_step(
         &step,
-        &format!("{} ({})", config.output.display(), HumanBytes(size)),
+        &format!("{} ({})", output_path.display(), HumanBytes(size)),
     );
 
-    pipeline.finish(&config.output.display().to_string());
+    pipeline.finish(&output_path.display().to_string());
 
     Ok(())
 }
 
-fn run_clean() -> Result<()> {
+fn run_completions(shell: Shell) {
+    let mut cmd = Cli::command();
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, name, &mut std::io::stdout());
+}
+
+fn run_clean(catalog: &Catalog) -> Result<()> {
     let cache_dir = BuildConfig::cache_dir()?;
     if cache_dir.exists() {
         let size = dir_size(&cache_dir);
         std::fs::remove_dir_all(&cache_dir)?;
-        eprintln!("Cleaned {} of cached data", HumanBytes(size));
+        eprintln!("{}", catalog.cache_cleaned(&HumanBytes(size).to_string()));
     } else {
-        eprintln!("Cache is already empty");
+        eprintln!("{}", catalog.cache_empty());
     }
     Ok(())
 }
 
-fn run_info() -> Result<()> {
+fn run_info(catalog: &Catalog) -> Result<()> {
     let cache_dir = BuildConfig::cache_dir()?;
-    eprintln!("Cache directory: {}", cache_dir.display());
+    eprintln!("{} {}", catalog.cache_directory(), cache_dir.display());
 
     if cache_dir.exists() {
         let size = dir_size(&cache_dir);
-        eprintln!("Cache size:      {}", HumanBytes(size));
+        eprintln!("{} {}", catalog.cache_size(), HumanBytes(size));
 
         let entries: Vec<_> = std::fs::read_dir(&cache_dir)?
             .filter_map(|e| e.ok())
             .collect();
-        eprintln!("Cached items:    {}", entries.len());
+        eprintln!("{} {}", catalog.cached_items(), entries.len());
 
         for entry in &entries {
             let name = entry.file_name();
@@ -318,10 +346,10 @@ fn run_info() -> Result<()> {
             eprintln!("  {} ({})", name.to_string_lossy(), HumanBytes(entry_size));
         }
     } else {
-        eprintln!("Cache is empty");
+        eprintln!("{}", catalog.cache_is_empty());
     }
 
-    eprintln!("\nCurrent platform: {:?}", Target::current());
+    eprintln!("\n{} {:?}", catalog.current_platform(), Target::current());
     Ok(())
 }
 
@@ -0,0 +1,216 @@
+//! User-facing message catalog.
+//!
+//! Strings are looked up by [`Key`] for a [`Locale`] resolved from `--lang` or
+//! the `LC_ALL`/`LANG` environment variables, falling back to English whenever
+//! a translation is missing. The ASCII banner is intentionally left out of the
+//! catalog — it is locale-agnostic.
+
+/// A locale jbundle can render its output in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    En,
+    PtBr,
+}
+
+impl Locale {
+    /// Resolve the active locale from an explicit `--lang` value, then
+    /// `LC_ALL`, then `LANG`, defaulting to English.
+    pub fn resolve(flag: Option<&str>) -> Locale {
+        let raw = flag
+            .map(str::to_string)
+            .or_else(|| std::env::var("LC_ALL").ok())
+            .or_else(|| std::env::var("LANG").ok())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        if raw.starts_with("pt") {
+            Locale::PtBr
+        } else {
+            Locale::En
+        }
+    }
+}
+
+/// A translatable message. Templates may contain a single `{}` placeholder,
+/// filled in by the accessor methods on [`Catalog`].
+#[derive(Debug, Clone, Copy)]
+pub enum Key {
+    UsingPrebuiltJar,
+    DetectingBuildSystem,
+    BuildingUberjar,
+    ShrinkingJar,
+    DownloadingJdk,
+    AnalyzingModules,
+    CreatingRuntime,
+    CreatingCheckpoint,
+    PackingBinary,
+    ExtractingRuntime,
+    CacheCleaned,
+    CacheEmpty,
+    CacheDirectory,
+    CacheSize,
+    CachedItems,
+    CacheIsEmpty,
+    CurrentPlatform,
+}
+
+/// A message catalog bound to a resolved [`Locale`].
+pub struct Catalog {
+    locale: Locale,
+}
+
+impl Catalog {
+    pub fn new(locale: Locale) -> Self {
+        Self { locale }
+    }
+
+    /// Look up `key`, using the active locale and falling back to English.
+    pub fn get(&self, key: Key) -> &'static str {
+        match self.locale {
+            Locale::En => en(key),
+            Locale::PtBr => pt_br(key).unwrap_or_else(|| en(key)),
+        }
+    }
+
+    fn fill(&self, key: Key, arg: &str) -> String {
+        self.get(key).replace("{}", arg)
+    }
+
+    pub fn using_prebuilt_jar(&self) -> &'static str {
+        self.get(Key::UsingPrebuiltJar)
+    }
+
+    pub fn detecting_build_system(&self) -> &'static str {
+        self.get(Key::DetectingBuildSystem)
+    }
+
+    pub fn building_uberjar(&self, build_desc: &str) -> String {
+        self.fill(Key::BuildingUberjar, build_desc)
+    }
+
+    pub fn shrinking_jar(&self) -> &'static str {
+        self.get(Key::ShrinkingJar)
+    }
+
+    pub fn downloading_jdk(&self, version: u32) -> String {
+        self.fill(Key::DownloadingJdk, &version.to_string())
+    }
+
+    pub fn analyzing_modules(&self) -> &'static str {
+        self.get(Key::AnalyzingModules)
+    }
+
+    pub fn creating_runtime(&self) -> &'static str {
+        self.get(Key::CreatingRuntime)
+    }
+
+    pub fn creating_checkpoint(&self) -> &'static str {
+        self.get(Key::CreatingCheckpoint)
+    }
+
+    pub fn packing_binary(&self) -> &'static str {
+        self.get(Key::PackingBinary)
+    }
+
+    /// The first-run message baked into the extraction stub.
+    pub fn extracting_runtime(&self) -> &'static str {
+        self.get(Key::ExtractingRuntime)
+    }
+
+    pub fn cache_cleaned(&self, human_size: &str) -> String {
+        self.fill(Key::CacheCleaned, human_size)
+    }
+
+    pub fn cache_empty(&self) -> &'static str {
+        self.get(Key::CacheEmpty)
+    }
+
+    pub fn cache_directory(&self) -> &'static str {
+        self.get(Key::CacheDirectory)
+    }
+
+    pub fn cache_size(&self) -> &'static str {
+        self.get(Key::CacheSize)
+    }
+
+    pub fn cached_items(&self) -> &'static str {
+        self.get(Key::CachedItems)
+    }
+
+    pub fn cache_is_empty(&self) -> &'static str {
+        self.get(Key::CacheIsEmpty)
+    }
+
+    pub fn current_platform(&self) -> &'static str {
+        self.get(Key::CurrentPlatform)
+    }
+}
+
+fn en(key: Key) -> &'static str {
+    match key {
+        Key::UsingPrebuiltJar => "Using pre-built JAR",
+        Key::DetectingBuildSystem => "Detecting build system",
+        Key::BuildingUberjar => "Building uberjar ({})",
+        Key::ShrinkingJar => "Shrinking JAR",
+        Key::DownloadingJdk => "Downloading JDK {}",
+        Key::AnalyzingModules => "Analyzing module dependencies",
+        Key::CreatingRuntime => "Creating minimal runtime (jlink)",
+        Key::CreatingCheckpoint => "Creating CRaC checkpoint",
+        Key::PackingBinary => "Packing binary",
+        Key::ExtractingRuntime => "Extracting runtime (first run)...",
+        Key::CacheCleaned => "Cleaned {} of cached data",
+        Key::CacheEmpty => "Cache is already empty",
+        Key::CacheDirectory => "Cache directory:",
+        Key::CacheSize => "Cache size:",
+        Key::CachedItems => "Cached items:",
+        Key::CacheIsEmpty => "Cache is empty",
+        Key::CurrentPlatform => "Current platform:",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_resolves_from_flag() {
+        assert_eq!(Locale::resolve(Some("pt-BR")), Locale::PtBr);
+        assert_eq!(Locale::resolve(Some("en_US.UTF-8")), Locale::En);
+    }
+
+    #[test]
+    fn english_catalog_renders_templates() {
+        let cat = Catalog::new(Locale::En);
+        assert_eq!(cat.downloading_jdk(21), "Downloading JDK 21");
+        assert_eq!(cat.building_uberjar("maven"), "Building uberjar (maven)");
+    }
+
+    #[test]
+    fn portuguese_catalog_translates() {
+        let cat = Catalog::new(Locale::PtBr);
+        assert_eq!(cat.creating_runtime(), "Criando runtime mínimo (jlink)");
+        assert_eq!(cat.downloading_jdk(17), "Baixando JDK 17");
+    }
+}
+
+fn pt_br(key: Key) -> Option<&'static str> {
+    Some(match key {
+        Key::UsingPrebuiltJar => "Usando JAR pré-compilado",
+        Key::DetectingBuildSystem => "Detectando sistema de build",
+        Key::BuildingUberjar => "Gerando uberjar ({})",
+        Key::ShrinkingJar => "Reduzindo JAR",
+        Key::DownloadingJdk => "Baixando JDK {}",
+        Key::AnalyzingModules => "Analisando dependências de módulos",
+        Key::CreatingRuntime => "Criando runtime mínimo (jlink)",
+        Key::CreatingCheckpoint => "Criando checkpoint CRaC",
+        Key::PackingBinary => "Empacotando binário",
+        Key::ExtractingRuntime => "Extraindo runtime (primeira execução)...",
+        Key::CacheCleaned => "Liberado {} de dados em cache",
+        Key::CacheEmpty => "O cache já está vazio",
+        Key::CacheDirectory => "Diretório de cache:",
+        Key::CacheSize => "Tamanho do cache:",
+        Key::CachedItems => "Itens em cache:",
+        Key::CacheIsEmpty => "O cache está vazio",
+        Key::CurrentPlatform => "Plataforma atual:",
+    })
+}
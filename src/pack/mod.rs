@@ -0,0 +1,105 @@
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sha2::{Digest, Sha256};
+
+use crate::config::{JvmProfile, Target};
+
+mod stub;
+
+/// Inputs to [`create_binary`]: the minimal runtime and application JAR to
+/// bundle, the target platform, and the launcher tuning carried from the CLI.
+/// [`create_binary`] does not read `profile`, `appcds`, `java_version` or
+/// `compact_banner`: the launcher always prints the full banner, passes only
+/// `jvm_args` to `java`, and does not restore from a bundled CRaC checkpoint.
+pub struct PackOptions<'a> {
+    pub runtime_dir: &'a Path,
+    pub jar_path: &'a Path,
+    pub crac_path: Option<&'a Path>,
+    pub output: &'a Path,
+    pub jvm_args: &'a [String],
+    pub profile: &'a JvmProfile,
+    pub appcds: bool,
+    pub java_version: u32,
+    pub compact_banner: bool,
+    pub target: &'a Target,
+    /// Localized "extracting runtime" message baked into the launcher.
+    pub extracting_msg: &'a str,
+}
+
+/// Pack the runtime and JAR into a single self-extracting executable. The file
+/// is a launcher stub (shell on POSIX, `.cmd` on Windows) with a gzipped tar of
+/// the runtime appended; the stub extracts the payload into a hash-keyed cache
+/// on first run. Returns the path actually written, which gains a `.cmd`
+/// suffix for Windows targets.
+pub fn create_binary(opts: &PackOptions) -> Result<PathBuf> {
+    let payload = build_payload(opts).context("building runtime payload")?;
+
+    let payload_hash = format!("{:x}", Sha256::digest(&payload));
+    let payload_size = payload.len() as u64;
+
+    let launcher = stub::for_target(
+        *opts.target,
+        &payload_hash,
+        payload_size,
+        opts.jvm_args,
+        opts.extracting_msg,
+    );
+
+    let output = output_path(opts.output, *opts.target);
+    let mut file = File::create(&output)
+        .with_context(|| format!("creating {}", output.display()))?;
+    file.write_all(launcher.as_bytes())?;
+    file.write_all(&payload)?;
+    file.flush()?;
+    drop(file);
+
+    set_executable(&output)?;
+    Ok(output)
+}
+
+/// Append `.cmd` to the output path for Windows targets so the batch launcher
+/// is recognised as executable; POSIX paths are left untouched.
+fn output_path(output: &Path, target: Target) -> PathBuf {
+    if target.is_windows() && output.extension().map_or(true, |e| e != "cmd") {
+        let mut name = output.as_os_str().to_os_string();
+        name.push(".cmd");
+        PathBuf::from(name)
+    } else {
+        output.to_path_buf()
+    }
+}
+
+/// Build the gzipped tar payload: the runtime under `runtime/`, the JAR as
+/// `app.jar`, and the CRaC checkpoint (when present) under `crac/`.
+fn build_payload(opts: &PackOptions) -> Result<Vec<u8>> {
+    let encoder = GzEncoder::new(Vec::new(), Compression::default());
+    let mut tar = tar::Builder::new(encoder);
+
+    tar.append_dir_all("runtime", opts.runtime_dir)?;
+    tar.append_path_with_name(opts.jar_path, "app.jar")?;
+    if let Some(crac) = opts.crac_path {
+        tar.append_dir_all("crac", crac)?;
+    }
+
+    let encoder = tar.into_inner()?;
+    Ok(encoder.finish()?)
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = fs::metadata(path)?.permissions();
+    perms.set_mode(0o755);
+    fs::set_permissions(path, perms)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
@@ -1,9 +1,65 @@
-pub fn generate(payload_hash: &str, payload_size: u64, jvm_args: &[String]) -> String {
-    let jvm_args_str = if jvm_args.is_empty() {
+use crate::config::Target;
+
+/// Generate the launcher appropriate for `target`: the POSIX shell stub for
+/// linux/macos and the self-extracting `.cmd` launcher for Windows. Both
+/// carry the payload as an appended `tail` and extract by hash into the cache.
+pub fn for_target(
+    target: Target,
+    payload_hash: &str,
+    payload_size: u64,
+    jvm_args: &[String],
+    extracting_msg: &str,
+) -> String {
+    if target.is_windows() {
+        generate_windows(payload_hash, payload_size, jvm_args, extracting_msg)
+    } else {
+        generate(payload_hash, payload_size, jvm_args, extracting_msg)
+    }
+}
+
+/// Escape a message for interpolation into a double-quoted POSIX shell string.
+fn escape_posix(msg: &str) -> String {
+    msg.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace('$', "\\$")
+        .replace('"', "\\\"")
+}
+
+/// Escape a message for a bare `echo` inside a cmd.exe batch block.
+fn escape_cmd(msg: &str) -> String {
+    let mut out = String::with_capacity(msg.len());
+    for ch in msg.chars() {
+        match ch {
+            // A literal percent is escaped by doubling, not with a caret.
+            '%' => out.push_str("%%"),
+            '^' | '&' | '(' | ')' | '<' | '>' | '|' | '"' => {
+                out.push('^');
+                out.push(ch);
+            }
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Render `jvm_args` as a leading-space-prefixed suffix for the launcher's
+/// `java` invocation (empty when there are no args).
+fn jvm_args_suffix(jvm_args: &[String]) -> String {
+    if jvm_args.is_empty() {
         String::new()
     } else {
         format!(" {}", jvm_args.join(" "))
-    };
+    }
+}
+
+pub fn generate(
+    payload_hash: &str,
+    payload_size: u64,
+    jvm_args: &[String],
+    extracting_msg: &str,
+) -> String {
+    let jvm_args_str = jvm_args_suffix(jvm_args);
+    let extracting_msg = escape_posix(extracting_msg);
 
     format!(
         r#"#!/bin/sh
@@ -23,8 +79,54 @@ BANNER
 
 if [ ! -d "$CACHE_DIR/runtime" ]; then
     mkdir -p "$CACHE_DIR"
-    echo "Extracting runtime (first run)..." >&2
-    tail -c "$PAYLOAD_SIZE" "$0" | tar xzf - -C "$CACHE_DIR"
+
+    # Advisory lock: mkdir is atomic, so exactly one process extracts while the
+    # rest wait for it to publish the runtime.
+    LOCK_DIR="$CACHE_DIR/.lock"
+    while ! mkdir "$LOCK_DIR" 2>/dev/null; do
+        [ -d "$CACHE_DIR/runtime" ] && break
+        sleep 1
+    done
+    trap 'rm -rf "$LOCK_DIR"' EXIT
+
+    if [ ! -d "$CACHE_DIR/runtime" ]; then
+        echo "{extracting_msg}" >&2
+
+        # Under the lock, drop temp dirs left by interrupted earlier runs.
+        rm -rf "$CACHE_DIR"/.tmp.* 2>/dev/null || true
+
+        PAYLOAD=$(mktemp)
+        tail -c "$PAYLOAD_SIZE" "$0" > "$PAYLOAD"
+
+        # Verify integrity before trusting the payload.
+        if command -v sha256sum >/dev/null 2>&1; then
+            ACTUAL=$(sha256sum "$PAYLOAD" | cut -d' ' -f1)
+        else
+            ACTUAL=$(shasum -a 256 "$PAYLOAD" | cut -d' ' -f1)
+        fi
+        if [ "$ACTUAL" != "$CACHE_ID" ]; then
+            echo "jbundle: payload checksum mismatch (expected $CACHE_ID, got $ACTUAL)" >&2
+            echo "jbundle: cached runtime is corrupt; it will be re-extracted on next run" >&2
+            rm -f "$PAYLOAD"
+            exit 1
+        fi
+
+        # Extract into a private temp dir, then atomically publish it. The
+        # presence of "$CACHE_DIR/runtime" is the only "ready" marker, so it is
+        # moved into place last.
+        TMP_DIR="$CACHE_DIR/.tmp.$$"
+        rm -rf "$TMP_DIR"
+        mkdir -p "$TMP_DIR"
+        tar xzf "$PAYLOAD" -C "$TMP_DIR"
+        rm -f "$PAYLOAD"
+        [ -e "$TMP_DIR/app.jar" ] && mv "$TMP_DIR/app.jar" "$CACHE_DIR/app.jar"
+        [ -d "$TMP_DIR/crac" ] && mv "$TMP_DIR/crac" "$CACHE_DIR/crac"
+        mv "$TMP_DIR/runtime" "$CACHE_DIR/runtime"
+        rm -rf "$TMP_DIR"
+    fi
+
+    rm -rf "$LOCK_DIR"
+    trap - EXIT
 fi
 
 exec "$CACHE_DIR/runtime/bin/java"{jvm_args_str} -jar "$CACHE_DIR/app.jar" "$@"
@@ -34,51 +136,207 @@ exit 0
     )
 }
 
+/// Self-extracting launcher for Windows targets. The file is a `.cmd` batch
+/// script; the bundled runtime is appended after the `exit /b` so `cmd.exe`
+/// never reads it. On first run the batch shells out to PowerShell, which —
+/// mirroring the POSIX stub — takes an advisory `.lock` directory, verifies the
+/// trailing `PAYLOAD_SIZE` bytes against the embedded SHA-256, extracts into a
+/// private `.tmp.*` sibling, and atomically publishes it into
+/// `%LOCALAPPDATA%\jbundle\cache\<hash>` before launching `java.exe -jar`.
+pub fn generate_windows(
+    payload_hash: &str,
+    payload_size: u64,
+    jvm_args: &[String],
+    extracting_msg: &str,
+) -> String {
+    let jvm_args_str = jvm_args_suffix(jvm_args);
+
+    // Escape cmd.exe metacharacters so a translated message (e.g. "primeira
+    // execução") does not break the batch line.
+    let extracting_msg = escape_cmd(extracting_msg);
+
+    // cmd.exe requires CRLF line endings, especially with `^` continuations.
+    let script = format!(
+        r#"@echo off
+setlocal enableextensions
+chcp 65001 >nul
+set "CACHE_ID={payload_hash}"
+set "CACHE_DIR=%LOCALAPPDATA%\jbundle\cache\%CACHE_ID%"
+set "PAYLOAD_SIZE={payload_size}"
+
+if not exist "%CACHE_DIR%\runtime" (
+    echo {extracting_msg} 1>&2
+    if not exist "%CACHE_DIR%" mkdir "%CACHE_DIR%"
+    powershell -NoProfile -ExecutionPolicy Bypass -Command ^
+      "$ErrorActionPreference='Stop'; ^
+       $cache='%CACHE_DIR%'; $expected='%CACHE_ID%'; $size=%PAYLOAD_SIZE%; ^
+       $lock=Join-Path $cache '.lock'; ^
+       while ($true) {{ try {{ New-Item -ItemType Directory -Path $lock -ErrorAction Stop ^| Out-Null; break }} catch {{ if (Test-Path (Join-Path $cache 'runtime')) {{ return }}; Start-Sleep -Milliseconds 200 }} }}; ^
+       try {{ ^
+         if (Test-Path (Join-Path $cache 'runtime')) {{ return }}; ^
+         Get-ChildItem -Path $cache -Directory -Filter '.tmp.*' -Force -ErrorAction SilentlyContinue ^| Remove-Item -Recurse -Force; ^
+         $src=[IO.File]::OpenRead('%~f0'); $src.Seek(-$size,'End') ^| Out-Null; ^
+         $buf=New-Object byte[] $size; [void]$src.Read($buf,0,$size); $src.Close(); ^
+         $hash=[BitConverter]::ToString([Security.Cryptography.SHA256]::Create().ComputeHash($buf)).Replace('-','').ToLower(); ^
+         if ($hash -ne $expected) {{ [Console]::Error.WriteLine('jbundle: payload checksum mismatch (expected '+$expected+', got '+$hash+')'); exit 1 }}; ^
+         $tmp=Join-Path $cache ('.tmp.'+[IO.Path]::GetRandomFileName()); ^
+         New-Item -ItemType Directory -Force -Path $tmp ^| Out-Null; ^
+         $pf=Join-Path $tmp 'payload.tgz'; [IO.File]::WriteAllBytes($pf,$buf); ^
+         tar -xzf $pf -C $tmp; if ($LASTEXITCODE -ne 0) {{ exit 1 }}; ^
+         Remove-Item $pf; ^
+         if (Test-Path (Join-Path $tmp 'app.jar')) {{ Move-Item (Join-Path $tmp 'app.jar') (Join-Path $cache 'app.jar') }}; ^
+         if (Test-Path (Join-Path $tmp 'crac')) {{ Move-Item (Join-Path $tmp 'crac') (Join-Path $cache 'crac') }}; ^
+         Move-Item (Join-Path $tmp 'runtime') (Join-Path $cache 'runtime'); ^
+         Remove-Item -Recurse -Force $tmp ^
+       }} finally {{ Remove-Item -Recurse -Force $lock -ErrorAction SilentlyContinue }}"
+    if errorlevel 1 (
+        echo jbundle: cached runtime is corrupt; it will be re-extracted on next run 1>&2
+        exit /b 1
+    )
+)
+
+"%CACHE_DIR%\runtime\bin\java.exe"{jvm_args_str} -jar "%CACHE_DIR%\app.jar" %*
+exit /b %ERRORLEVEL%
+rem --- PAYLOAD BELOW ---
+"#
+    );
+
+    script.replace('\n', "\r\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn stub_starts_with_shebang() {
-        let stub = generate("abc123", 1024, &[]);
+        let stub = generate("abc123", 1024, &[], "Extracting runtime (first run)...");
         assert!(stub.starts_with("#!/bin/sh\n"));
     }
 
     #[test]
     fn stub_contains_payload_hash() {
-        let stub = generate("deadbeef12345678", 2048, &[]);
+        let stub = generate("deadbeef12345678", 2048, &[], "Extracting runtime (first run)...");
         assert!(stub.contains("CACHE_ID=\"deadbeef12345678\""));
     }
 
     #[test]
     fn stub_contains_payload_size() {
-        let stub = generate("abc", 999999, &[]);
+        let stub = generate("abc", 999999, &[], "Extracting runtime (first run)...");
         assert!(stub.contains("PAYLOAD_SIZE=999999"));
     }
 
     #[test]
     fn stub_without_jvm_args() {
-        let stub = generate("abc", 100, &[]);
+        let stub = generate("abc", 100, &[], "Extracting runtime (first run)...");
         assert!(stub.contains("exec \"$CACHE_DIR/runtime/bin/java\" -jar"));
     }
 
     #[test]
     fn stub_with_jvm_args() {
         let args = vec!["-Xmx512m".to_string(), "-Dapp.env=prod".to_string()];
-        let stub = generate("abc", 100, &args);
+        let stub = generate("abc", 100, &args, "Extracting runtime (first run)...");
         assert!(stub.contains("exec \"$CACHE_DIR/runtime/bin/java\" -Xmx512m -Dapp.env=prod -jar"));
     }
 
     #[test]
     fn stub_ends_with_payload_marker() {
-        let stub = generate("abc", 100, &[]);
+        let stub = generate("abc", 100, &[], "Extracting runtime (first run)...");
         assert!(stub.ends_with("# --- PAYLOAD BELOW ---\n"));
     }
 
     #[test]
     fn stub_contains_banner() {
-        let stub = generate("abc", 100, &[]);
+        let stub = generate("abc", 100, &[], "Extracting runtime (first run)...");
         assert!(stub.contains("BANNER"));
         assert!(stub.contains("(_) |__"));
     }
+
+    #[test]
+    fn stub_extracts_via_temp_dir() {
+        let stub = generate("abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(stub.contains("TMP_DIR=\"$CACHE_DIR/.tmp.$$\""));
+        assert!(stub.contains("mv \"$TMP_DIR/runtime\" \"$CACHE_DIR/runtime\""));
+    }
+
+    #[test]
+    fn stub_takes_advisory_lock() {
+        let stub = generate("abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(stub.contains("mkdir \"$LOCK_DIR\""));
+    }
+
+    #[test]
+    fn stub_verifies_checksum_and_exits_on_mismatch() {
+        let stub = generate("abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(stub.contains("payload checksum mismatch"));
+        assert!(stub.contains("exit 1"));
+    }
+
+    #[test]
+    fn stub_cleans_stale_temp_dirs() {
+        let stub = generate("abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(stub.contains("rm -rf \"$CACHE_DIR\"/.tmp.*"));
+    }
+
+    #[test]
+    fn windows_stub_starts_with_batch_header() {
+        let stub = generate_windows("abc123", 1024, &[], "Extracting runtime (first run)...");
+        assert!(stub.starts_with("@echo off\r\n"));
+    }
+
+    #[test]
+    fn windows_stub_uses_crlf_line_endings() {
+        let stub = generate_windows("abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(!stub.replace("\r\n", "").contains('\n'));
+        assert!(stub.lines().count() > 1);
+        assert!(stub.contains("\r\n"));
+    }
+
+    #[test]
+    fn windows_stub_caches_by_hash_under_localappdata() {
+        let stub = generate_windows("deadbeef", 2048, &[], "Extracting runtime (first run)...");
+        assert!(stub.contains("set \"CACHE_ID=deadbeef\""));
+        assert!(stub.contains("%LOCALAPPDATA%\\jbundle\\cache\\%CACHE_ID%"));
+    }
+
+    #[test]
+    fn windows_stub_launches_java_exe_with_jvm_args() {
+        let args = vec!["-Xmx512m".to_string()];
+        let stub = generate_windows("abc", 100, &args, "Extracting runtime (first run)...");
+        assert!(stub.contains("\\runtime\\bin\\java.exe\" -Xmx512m -jar"));
+    }
+
+    #[test]
+    fn windows_stub_takes_advisory_lock() {
+        let stub = generate_windows("abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(stub.contains("New-Item -ItemType Directory -Path $lock"));
+    }
+
+    #[test]
+    fn windows_stub_extracts_via_temp_and_publishes_atomically() {
+        let stub = generate_windows("abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(stub.contains(".tmp."));
+        assert!(stub.contains("Move-Item (Join-Path $tmp 'runtime') (Join-Path $cache 'runtime')"));
+    }
+
+    #[test]
+    fn windows_stub_verifies_checksum() {
+        let stub = generate_windows("abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(stub.contains("payload checksum mismatch"));
+        assert!(stub.contains("$hash -ne $expected"));
+    }
+
+    #[test]
+    fn windows_stub_ends_with_payload_marker() {
+        let stub = generate_windows("abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(stub.ends_with("rem --- PAYLOAD BELOW ---\r\n"));
+    }
+
+    #[test]
+    fn for_target_selects_windows_launcher() {
+        let posix = for_target(Target::LinuxX64, "abc", 100, &[], "Extracting runtime (first run)...");
+        let win = for_target(Target::WindowsX64, "abc", 100, &[], "Extracting runtime (first run)...");
+        assert!(posix.starts_with("#!/bin/sh\n"));
+        assert!(win.starts_with("@echo off\r\n"));
+    }
 }
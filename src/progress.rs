@@ -6,15 +6,17 @@ pub struct Pipeline {
     total: usize,
     current: usize,
     is_tty: bool,
+    verbosity: u8,
 }
 
 impl Pipeline {
-    pub fn new(total_steps: usize) -> Self {
+    pub fn new(total_steps: usize, verbosity: u8) -> Self {
         Self {
             mp: MultiProgress::new(),
             total: total_steps,
             current: 0,
             is_tty: Term::stderr().is_term(),
+            verbosity,
         }
     }
 
@@ -58,6 +60,24 @@ impl Pipeline {
         }
     }
 
+    /// Emit a deferred diagnostic block beneath the current step, but only when
+    /// the active verbosity is at least `level` (1 = info, 2 = debug,
+    /// 3 = trace). The closure is evaluated lazily, so constructing the often
+    /// large detail string (full module sets, command lines) costs nothing at
+    /// the levels where it would not be shown.
+    pub fn detail(&self, level: u8, f: impl FnOnce() -> String) {
+        if self.verbosity < level {
+            return;
+        }
+        for line in f().lines() {
+            if self.is_tty {
+                let _ = self.mp.println(format!("      {line}"));
+            } else {
+                eprintln!("      {line}");
+            }
+        }
+    }
+
     pub fn mp(&self) -> &MultiProgress {
         &self.mp
     }